@@ -0,0 +1,143 @@
+use anyhow::Result;
+use tracing::{debug, info};
+
+use crate::fan::{set_fan_speed, MlnxFan};
+use crate::fan_curve::{FanCurve, MatrixPoint};
+use crate::is_mellanox_platform;
+use std::path::PathBuf;
+
+/// A sensor reading handed to an [`Adapter`] on each control tick.
+#[derive(Debug, Clone, Copy)]
+pub struct SensorReading {
+    /// Index of the driving thermal sensor.
+    pub index: usize,
+    /// Temperature in degrees Celsius.
+    pub temperature: f32,
+}
+
+/// Runtime settings threaded through the adapter on every call.
+#[derive(Debug, Clone)]
+pub struct Settings {
+    /// Whether automatic fan control is enabled.
+    pub enabled: bool,
+    /// Speed floor applied to every actuation.
+    pub min_speed: u32,
+}
+
+/// Abstraction over fan actuation so the control logic is decoupled from the
+/// concrete sysfs-writing backend.
+///
+/// The real hwmon implementation drives `/sys`, while [`DevModeFan`] logs the
+/// requested PWM and returns synthetic readings so the crate can run in CI and
+/// on developer laptops. Downstream integrators can supply BMC/ASIC-native
+/// adapters without touching the discovery code in `MlnxChassis`.
+pub trait Adapter: Send + Sync {
+    /// React to the enable toggle changing state.
+    fn on_enable_toggled(&self, settings: &Settings) -> Result<()>;
+
+    /// Drive the fans from the supplied sensor readings.
+    fn control_fan(&self, settings: &Settings, sensors: &[SensorReading]) -> Result<()>;
+}
+
+/// Hwmon-backed adapter writing PWM values directly to sysfs.
+pub struct HwmonAdapter {
+    hwmon_path: PathBuf,
+    pwm_index: usize,
+    curve: FanCurve,
+}
+
+impl HwmonAdapter {
+    pub fn new(hwmon_path: PathBuf, pwm_index: usize, curve: FanCurve) -> Self {
+        Self {
+            hwmon_path,
+            pwm_index,
+            curve,
+        }
+    }
+}
+
+impl Adapter for HwmonAdapter {
+    fn on_enable_toggled(&self, settings: &Settings) -> Result<()> {
+        info!("Fan control {}", if settings.enabled { "enabled" } else { "disabled" });
+        Ok(())
+    }
+
+    fn control_fan(&self, settings: &Settings, sensors: &[SensorReading]) -> Result<()> {
+        if !settings.enabled {
+            return Ok(());
+        }
+        let hottest = sensors.iter().map(|s| s.temperature).fold(0.0_f32, f32::max);
+        let speed = self.curve.speed_for_temp(hottest).max(settings.min_speed);
+        set_fan_speed(&self.hwmon_path, self.pwm_index, speed)
+    }
+}
+
+/// Adapter used on non-Mellanox hosts: it logs requested PWM values and serves
+/// synthetic readings without touching `/sys`.
+pub struct DevModeFan {
+    curve: FanCurve,
+}
+
+impl DevModeFan {
+    pub fn new(curve: FanCurve) -> Self {
+        Self { curve }
+    }
+}
+
+impl Adapter for DevModeFan {
+    fn on_enable_toggled(&self, settings: &Settings) -> Result<()> {
+        debug!("[dev] enable toggled -> {}", settings.enabled);
+        Ok(())
+    }
+
+    fn control_fan(&self, settings: &Settings, sensors: &[SensorReading]) -> Result<()> {
+        if !settings.enabled {
+            return Ok(());
+        }
+        let hottest = sensors.iter().map(|s| s.temperature).fold(0.0_f32, f32::max);
+        let speed = self.curve.speed_for_temp(hottest).max(settings.min_speed);
+        debug!("[dev] would set fan speed to {}%", speed);
+        Ok(())
+    }
+}
+
+impl DevModeFan {
+    /// Return a synthetic reading so control loops can be exercised off hardware.
+    pub fn synthetic_reading(index: usize) -> SensorReading {
+        SensorReading {
+            index,
+            temperature: 40.0,
+        }
+    }
+}
+
+/// Default thermal policy used when a board descriptor does not supply its own
+/// curve: hold a 30% floor up to 40°C, then ramp to full speed by 75°C.
+fn default_fan_curve() -> FanCurve {
+    FanCurve::new(
+        vec![
+            MatrixPoint { temp: 40.0, speed: 30.0 },
+            MatrixPoint { temp: 60.0, speed: 60.0 },
+            MatrixPoint { temp: 75.0, speed: 100.0 },
+        ],
+        30.0,
+    )
+    .expect("built-in default fan curve is valid")
+}
+
+/// Select the adapter appropriate for the running host, driving actuation
+/// through the supplied [`FanCurve`].
+pub fn default_adapter_with_curve(fan: &MlnxFan, curve: FanCurve) -> Box<dyn Adapter> {
+    if is_mellanox_platform() {
+        if let Some((path, pwm)) = fan.pwm_channel() {
+            return Box::new(HwmonAdapter::new(path, pwm, curve));
+        }
+    }
+    Box::new(DevModeFan::new(curve))
+}
+
+/// Select the adapter appropriate for the running host using the built-in
+/// default fan curve.
+pub fn default_adapter(fan: &MlnxFan) -> Box<dyn Adapter> {
+    default_adapter_with_curve(fan, default_fan_curve())
+}
@@ -1,27 +1,38 @@
 use anyhow::{Context, Result};
-use glob::glob;
-use std::collections::HashMap;
-use std::fs;
 use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
 use crate::fan::MlnxFan;
+use crate::sysfs::SharedSysfs;
 use crate::thermal::MlnxThermal;
 
 pub struct MlnxChassis {
     fans: Vec<Box<dyn sonic_thermalctld::fan::Fan>>,
     fan_drawers: Vec<sonic_thermalctld::fan::FanDrawer>,
     thermals: Vec<Box<dyn sonic_thermalctld::thermal::Thermal>>,
+    config: Option<crate::config::PlatformConfig>,
+    eeprom: Option<crate::eeprom::SysEeprom>,
+    backend: SharedSysfs,
 }
 
 impl MlnxChassis {
     pub fn new() -> Result<Self> {
+        Self::with_backend(crate::sysfs::live())
+    }
+
+    /// Build a chassis whose discovery and drivers go through the supplied
+    /// [`SysfsBackend`](crate::sysfs::SysfsBackend), so the whole stack can run
+    /// against a recorded snapshot in CI.
+    pub fn with_backend(backend: SharedSysfs) -> Result<Self> {
         info!("Initializing Mellanox chassis");
 
         let mut chassis = Self {
             fans: Vec::new(),
             fan_drawers: Vec::new(),
             thermals: Vec::new(),
+            config: None,
+            eeprom: None,
+            backend,
         };
 
         chassis.discover_hwmon_devices()?;
@@ -35,17 +46,125 @@ impl MlnxChassis {
         Ok(chassis)
     }
 
-    fn discover_hwmon_devices(&mut self) -> Result<()> {
-        let hwmon_pattern = "/sys/class/hwmon/hwmon*";
+    /// Build a chassis from a parsed per-platform descriptor.
+    ///
+    /// Discovery binds the descriptor's thermal names and thresholds to the
+    /// sensors as they are created, so adding a new board is a data drop rather
+    /// than a recompile.
+    pub fn from_config(config: crate::config::PlatformConfig) -> Result<Self> {
+        info!(
+            "Initializing Mellanox chassis from descriptor: {} fans / {} drawers",
+            config.fan_count, config.drawer_count
+        );
 
-        for entry in glob(hwmon_pattern).context("Failed to read hwmon pattern")? {
-            match entry {
-                Ok(path) => {
-                    if let Err(e) = self.process_hwmon_device(&path) {
-                        warn!("Failed to process hwmon device {}: {}", path.display(), e);
-                    }
-                }
-                Err(e) => warn!("Failed to read hwmon entry: {}", e),
+        let mut chassis = Self {
+            fans: Vec::new(),
+            fan_drawers: Vec::new(),
+            thermals: Vec::new(),
+            config: Some(config),
+            eeprom: None,
+            backend: crate::sysfs::live(),
+        };
+
+        chassis.discover_configured_hwmon()?;
+        chassis.check_discovered_against_descriptor();
+
+        info!(
+            "Mellanox chassis initialized: {} fans, {} thermals",
+            chassis.fans.len(),
+            chassis.thermals.len()
+        );
+
+        Ok(chassis)
+    }
+
+    /// Drive discovery from the descriptor's `hwmon_paths` when it supplies
+    /// them, so supporting a new board is a data drop rather than a recompile.
+    /// Each configured path is taken as a hwmon device directory, resolved
+    /// relative to `/sys` unless it is already absolute. With no paths listed
+    /// we fall back to scanning the conventional `/sys/class/hwmon` root.
+    fn discover_configured_hwmon(&mut self) -> Result<()> {
+        let paths = self
+            .config
+            .as_ref()
+            .map(|c| c.hwmon_paths.clone())
+            .unwrap_or_default();
+
+        if paths.is_empty() {
+            return self.discover_hwmon_devices();
+        }
+
+        for rel in paths {
+            let path = if rel.is_absolute() {
+                rel
+            } else {
+                Path::new("/sys").join(rel)
+            };
+            if let Err(e) = self.process_hwmon_device(&path) {
+                warn!(
+                    "Failed to process configured hwmon device {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Warn when the number of discovered fans differs from the descriptor's
+    /// `fan_count`, or when the descriptor's fans do not divide evenly across
+    /// its drawers, so a stale descriptor surfaces at init rather than as
+    /// mysterious missing fans later. Drawers themselves are not discovered.
+    fn check_discovered_against_descriptor(&self) {
+        let Some(config) = self.config.as_ref() else {
+            return;
+        };
+
+        if self.fans.len() != config.fan_count {
+            warn!(
+                "descriptor declares {} fans but discovery found {}",
+                config.fan_count,
+                self.fans.len()
+            );
+        }
+
+        if config.drawer_count == 0 {
+            warn!("descriptor declares zero fan drawers");
+        } else if config.fan_count % config.drawer_count != 0 {
+            warn!(
+                "descriptor's {} fans do not divide evenly across {} drawers",
+                config.fan_count, config.drawer_count
+            );
+        }
+    }
+
+    /// Descriptor entry for the nth thermal sensor discovered so far, if any.
+    fn thermal_descriptor(&self, position: usize) -> Option<crate::config::ThermalDescriptor> {
+        self.config
+            .as_ref()
+            .and_then(|c| c.thermals.get(position).cloned())
+    }
+
+    fn discover_hwmon_devices(&mut self) -> Result<()> {
+        let hwmon_root = Path::new("/sys/class/hwmon");
+
+        let entries = self
+            .backend
+            .read_dir(hwmon_root)
+            .context("Failed to read hwmon directory")?;
+
+        for path in entries {
+            let is_hwmon = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.starts_with("hwmon"))
+                .unwrap_or(false);
+            if !is_hwmon {
+                continue;
+            }
+            if let Err(e) = self.process_hwmon_device(&path) {
+                warn!("Failed to process hwmon device {}: {}", path.display(), e);
             }
         }
 
@@ -69,21 +188,28 @@ impl MlnxChassis {
 
     fn read_hwmon_name(&self, hwmon_path: &Path) -> Result<String> {
         let name_path = hwmon_path.join("name");
-        fs::read_to_string(&name_path)
+        self.backend
+            .read_to_string(&name_path)
             .context("Failed to read hwmon name")
             .map(|s| s.trim().to_string())
     }
 
+    /// Basenames of the files in `hwmon_path`, via the active backend.
+    fn hwmon_entries(&self, hwmon_path: &Path) -> Result<Vec<String>> {
+        Ok(self
+            .backend
+            .read_dir(hwmon_path)?
+            .into_iter()
+            .filter_map(|p| p.file_name().map(|n| n.to_string_lossy().into_owned()))
+            .collect())
+    }
+
     fn discover_mlxsw_sensors(&mut self, hwmon_path: &Path, _name: &str) -> Result<()> {
         let mut temp_indices = Vec::new();
         let mut fan_indices = Vec::new();
         let mut pwm_indices = Vec::new();
 
-        for entry in fs::read_dir(hwmon_path)? {
-            let entry = entry?;
-            let filename = entry.file_name();
-            let filename_str = filename.to_string_lossy();
-
+        for filename_str in self.hwmon_entries(hwmon_path)? {
             if filename_str.starts_with("temp") && filename_str.ends_with("_input") {
                 if let Some(idx_str) = filename_str.strip_prefix("temp").and_then(|s| s.strip_suffix("_input")) {
                     if let Ok(idx) = idx_str.parse::<usize>() {
@@ -111,7 +237,15 @@ impl MlnxChassis {
 
         for temp_idx in temp_indices {
             let name = format!("Thermal {}", temp_idx);
-            let thermal = MlnxThermal::new(name, hwmon_path.to_path_buf(), temp_idx);
+            let mut thermal = MlnxThermal::with_backend(
+                name,
+                hwmon_path.to_path_buf(),
+                temp_idx,
+                self.backend.clone(),
+            );
+            if let Some(desc) = self.thermal_descriptor(self.thermals.len()) {
+                thermal = thermal.with_descriptor(&desc);
+            }
             self.thermals.push(Box::new(thermal));
             debug!("Added thermal sensor at temp{}", temp_idx);
         }
@@ -119,7 +253,13 @@ impl MlnxChassis {
         for (i, fan_idx) in fan_indices.iter().enumerate() {
             let name = format!("Fan {}", fan_idx);
             let pwm_idx = pwm_indices.get(i).copied();
-            let fan = MlnxFan::new(name, hwmon_path.to_path_buf(), *fan_idx, pwm_idx);
+            let fan = MlnxFan::with_backend(
+                name,
+                hwmon_path.to_path_buf(),
+                *fan_idx,
+                pwm_idx,
+                self.backend.clone(),
+            );
             self.fans.push(Box::new(fan));
             debug!("Added fan at fan{} with pwm{:?}", fan_idx, pwm_idx);
         }
@@ -130,11 +270,7 @@ impl MlnxChassis {
     fn discover_fans(&mut self, hwmon_path: &Path, name: &str) -> Result<()> {
         let mut fan_indices = Vec::new();
 
-        for entry in fs::read_dir(hwmon_path)? {
-            let entry = entry?;
-            let filename = entry.file_name();
-            let filename_str = filename.to_string_lossy();
-
+        for filename_str in self.hwmon_entries(hwmon_path)? {
             if filename_str.starts_with("fan") && filename_str.ends_with("_input") {
                 if let Some(idx_str) = filename_str.strip_prefix("fan").and_then(|s| s.strip_suffix("_input")) {
                     if let Ok(idx) = idx_str.parse::<usize>() {
@@ -148,7 +284,13 @@ impl MlnxChassis {
 
         for fan_idx in fan_indices {
             let fan_name = format!("{} Fan {}", name, fan_idx);
-            let fan = MlnxFan::new(fan_name, hwmon_path.to_path_buf(), fan_idx, None);
+            let fan = MlnxFan::with_backend(
+                fan_name,
+                hwmon_path.to_path_buf(),
+                fan_idx,
+                None,
+                self.backend.clone(),
+            );
             self.fans.push(Box::new(fan));
             debug!("Added fan {} at {}", fan_idx, hwmon_path.display());
         }
@@ -159,11 +301,7 @@ impl MlnxChassis {
     fn discover_generic_sensors(&mut self, hwmon_path: &Path, name: &str) -> Result<()> {
         let mut temp_indices = Vec::new();
 
-        for entry in fs::read_dir(hwmon_path)? {
-            let entry = entry?;
-            let filename = entry.file_name();
-            let filename_str = filename.to_string_lossy();
-
+        for filename_str in self.hwmon_entries(hwmon_path)? {
             if filename_str.starts_with("temp") && filename_str.ends_with("_input") {
                 if let Some(idx_str) = filename_str.strip_prefix("temp").and_then(|s| s.strip_suffix("_input")) {
                     if let Ok(idx) = idx_str.parse::<usize>() {
@@ -177,7 +315,15 @@ impl MlnxChassis {
 
         for temp_idx in temp_indices {
             let thermal_name = format!("{} Thermal {}", name, temp_idx);
-            let thermal = MlnxThermal::new(thermal_name, hwmon_path.to_path_buf(), temp_idx);
+            let mut thermal = MlnxThermal::with_backend(
+                thermal_name,
+                hwmon_path.to_path_buf(),
+                temp_idx,
+                self.backend.clone(),
+            );
+            if let Some(desc) = self.thermal_descriptor(self.thermals.len()) {
+                thermal = thermal.with_descriptor(&desc);
+            }
             self.thermals.push(Box::new(thermal));
             debug!("Added thermal {} at {}", temp_idx, hwmon_path.display());
         }
@@ -185,6 +331,35 @@ impl MlnxChassis {
         Ok(())
     }
 
+    /// Parse the ONIE TlvInfo syseeprom at the board's I2C sysfs path and cache
+    /// the hardware identity for the accessors below.
+    pub fn load_syseeprom(&mut self, path: &Path) -> Result<()> {
+        self.eeprom = Some(crate::eeprom::SysEeprom::read(path)?);
+        Ok(())
+    }
+
+    /// Chassis model number (ONIE Product Name), if the EEPROM was loaded.
+    pub fn get_model(&self) -> Option<&str> {
+        self.eeprom.as_ref().and_then(|e| e.model.as_deref())
+    }
+
+    /// Chassis serial number (ONIE Serial Number).
+    pub fn get_serial_number(&self) -> Option<&str> {
+        self.eeprom.as_ref().and_then(|e| e.serial.as_deref())
+    }
+
+    /// Chassis part number (ONIE Part Number).
+    pub fn get_part_number(&self) -> Option<&str> {
+        self.eeprom.as_ref().and_then(|e| e.part_number.as_deref())
+    }
+
+    /// Chassis hardware revision (ONIE Device Version).
+    pub fn get_hardware_revision(&self) -> Option<&str> {
+        self.eeprom
+            .as_ref()
+            .and_then(|e| e.hardware_revision.as_deref())
+    }
+
     pub fn get_fans(&self) -> &[Box<dyn sonic_thermalctld::fan::Fan>] {
         &self.fans
     }
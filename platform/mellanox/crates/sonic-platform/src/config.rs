@@ -0,0 +1,65 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::platform::Platform;
+
+/// Per-sensor description loaded from the platform descriptor.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThermalDescriptor {
+    /// Human-meaningful sensor name (e.g. "Ambient Port Side").
+    pub name: String,
+    /// Warning (high) threshold in degrees Celsius.
+    pub warning: Option<f32>,
+    /// Critical threshold in degrees Celsius.
+    pub critical: Option<f32>,
+    /// Shutdown threshold in degrees Celsius.
+    pub shutdown: Option<f32>,
+}
+
+/// Parsed `mlnx_platform.yaml` describing one board's hardware layout.
+///
+/// Loading a descriptor per SKU turns supporting a new board into a data drop
+/// rather than a recompile. `MlnxChassis::from_config` takes this config: it
+/// binds the listed hwmon paths and applies the thermal names and thresholds to
+/// sensors in discovery order. Fans are still discovered from sysfs and are not
+/// built from this config; `fan_count` and `drawer_count` are only checked
+/// against discovery.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlatformConfig {
+    /// Total number of fans on the board.
+    pub fan_count: usize,
+    /// Number of fan drawers.
+    pub drawer_count: usize,
+    /// Thermal sensors and their thresholds.
+    #[serde(default)]
+    pub thermals: Vec<ThermalDescriptor>,
+    /// Hwmon directories to bind, relative to `/sys` or absolute.
+    #[serde(default)]
+    pub hwmon_paths: Vec<PathBuf>,
+}
+
+impl PlatformConfig {
+    /// Conventional descriptor path for a detected platform.
+    pub fn path_for(platform: &Platform) -> PathBuf {
+        PathBuf::from(format!(
+            "/usr/share/sonic/device/{}/mlnx_platform.yaml",
+            platform
+        ))
+    }
+
+    /// Load and parse the descriptor for the detected platform.
+    pub fn load(platform: &Platform) -> Result<Self> {
+        Self::load_from(Self::path_for(platform))
+    }
+
+    /// Load and parse a descriptor from an explicit path.
+    pub fn load_from(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read platform descriptor {}", path.display()))?;
+        serde_yaml::from_str(&contents)
+            .with_context(|| format!("Failed to parse platform descriptor {}", path.display()))
+    }
+}
@@ -0,0 +1,118 @@
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// 8-byte identifier prefixing every ONIE TlvInfo EEPROM.
+const TLV_HEADER_ID: &[u8] = b"TlvInfo\0";
+
+// TLV record types of interest (see the ONIE TlvInfo specification).
+const TLV_PRODUCT_NAME: u8 = 0x21;
+const TLV_PART_NUMBER: u8 = 0x22;
+const TLV_SERIAL_NUMBER: u8 = 0x23;
+const TLV_DEVICE_VERSION: u8 = 0x26;
+const TLV_CRC32: u8 = 0xFE;
+
+/// Chassis hardware identity parsed from the ONIE TlvInfo EEPROM.
+#[derive(Debug, Clone, Default)]
+pub struct SysEeprom {
+    /// Product Name (TLV 0x21) — the chassis model number.
+    pub model: Option<String>,
+    /// Serial Number (TLV 0x23).
+    pub serial: Option<String>,
+    /// Part Number (TLV 0x22).
+    pub part_number: Option<String>,
+    /// Device Version (TLV 0x26) — the hardware revision.
+    pub hardware_revision: Option<String>,
+}
+
+impl SysEeprom {
+    /// Read and parse the syseeprom blob at the board's I2C sysfs path.
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let blob = fs::read(path)
+            .with_context(|| format!("Failed to read syseeprom {}", path.display()))?;
+        Self::parse(&blob)
+    }
+
+    /// Parse an in-memory TlvInfo blob.
+    pub fn parse(blob: &[u8]) -> Result<Self> {
+        if blob.len() < 11 {
+            bail!("syseeprom too short to hold a TlvInfo header");
+        }
+        if &blob[0..8] != TLV_HEADER_ID {
+            bail!("syseeprom missing TlvInfo header");
+        }
+
+        // Byte 8 is the format version; bytes 9..11 are the big-endian length
+        // of the TLV records that follow the header.
+        let total_len = u16::from_be_bytes([blob[9], blob[10]]) as usize;
+        let records_end = 11 + total_len;
+        if records_end > blob.len() {
+            bail!("syseeprom total length {} exceeds blob", total_len);
+        }
+
+        let mut eeprom = SysEeprom::default();
+        let mut pos = 11;
+        while pos + 2 <= records_end {
+            let tlv_type = blob[pos];
+            let len = blob[pos + 1] as usize;
+            let value_start = pos + 2;
+            let value_end = value_start + len;
+            if value_end > records_end {
+                bail!("syseeprom TLV 0x{:02x} overruns record area", tlv_type);
+            }
+            let value = &blob[value_start..value_end];
+
+            match tlv_type {
+                TLV_PRODUCT_NAME => eeprom.model = Some(decode(value)),
+                TLV_PART_NUMBER => eeprom.part_number = Some(decode(value)),
+                TLV_SERIAL_NUMBER => eeprom.serial = Some(decode(value)),
+                TLV_DEVICE_VERSION => eeprom.hardware_revision = Some(decode(value)),
+                TLV_CRC32 => {
+                    // The CRC covers the header and every record up to and
+                    // including this TLV's type/length, but not the 4-byte
+                    // value itself.
+                    if value.len() != 4 {
+                        bail!(
+                            "syseeprom CRC-32 TLV has length {}, expected 4",
+                            value.len()
+                        );
+                    }
+                    let expected = u32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+                    let actual = crc32(&blob[..value_start]);
+                    if expected != actual {
+                        bail!(
+                            "syseeprom CRC mismatch: expected {:08x}, computed {:08x}",
+                            expected,
+                            actual
+                        );
+                    }
+                    break;
+                }
+                _ => {}
+            }
+
+            pos = value_end;
+        }
+
+        Ok(eeprom)
+    }
+}
+
+/// Decode a TLV value as a trimmed UTF-8 string.
+fn decode(value: &[u8]) -> String {
+    String::from_utf8_lossy(value).trim().to_string()
+}
+
+/// Standard CRC-32 (IEEE 802.3) as used by the ONIE CRC-32 TLV.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
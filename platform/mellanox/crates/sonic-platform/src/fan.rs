@@ -4,6 +4,8 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU32, Ordering};
 
+use crate::sysfs::SharedSysfs;
+
 static BAD_FAN_COUNT: AtomicU32 = AtomicU32::new(0);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -196,26 +198,67 @@ impl FanDrawer {
     }
 }
 
+/// Default tolerance (in percentage points) when none is configured.
+pub const DEFAULT_TOLERANCE: u32 = 20;
+
+/// Fallback RPM ceiling used when the kernel exposes no `fanN_max`, or one
+/// that reads as zero.
+const FALLBACK_MAX_RPM: u32 = 25000;
+
 pub struct MlnxFan {
     name: String,
     hwmon_path: PathBuf,
     fan_index: usize,
     pwm_index: Option<usize>,
+    max_rpm: Option<u32>,
+    tolerance: u32,
+    backend: SharedSysfs,
 }
 
 impl MlnxFan {
+    /// Construct a fan driven through the live sysfs backend.
     pub fn new(name: String, hwmon_path: PathBuf, fan_index: usize, pwm_index: Option<usize>) -> Self {
-        Self {
+        Self::with_backend(name, hwmon_path, fan_index, pwm_index, crate::sysfs::live())
+    }
+
+    /// Construct a fan backed by the supplied [`SysfsBackend`](crate::sysfs::SysfsBackend).
+    pub fn with_backend(
+        name: String,
+        hwmon_path: PathBuf,
+        fan_index: usize,
+        pwm_index: Option<usize>,
+        backend: SharedSysfs,
+    ) -> Self {
+        let mut fan = Self {
             name,
             hwmon_path,
             fan_index,
             pwm_index,
-        }
+            max_rpm: None,
+            tolerance: DEFAULT_TOLERANCE,
+            backend,
+        };
+        // Cache the kernel-provided RPM ceiling at construction so percentage
+        // scaling does not re-read it on every poll.
+        fan.max_rpm = fan.read_sysfs_u32(&format!("fan{}_max", fan.fan_index)).ok();
+        fan
+    }
+
+    /// Override the under/over-speed tolerance in percentage points.
+    pub fn with_tolerance(mut self, tolerance: u32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Return this fan's hwmon directory and PWM index, if it has one.
+    pub fn pwm_channel(&self) -> Option<(PathBuf, usize)> {
+        self.pwm_index.map(|idx| (self.hwmon_path.clone(), idx))
     }
 
     fn read_sysfs_value(&self, filename: &str) -> Result<String> {
         let path = self.hwmon_path.join(filename);
-        fs::read_to_string(&path)
+        self.backend
+            .read_to_string(&path)
             .with_context(|| format!("Failed to read {}", path.display()))
             .map(|s| s.trim().to_string())
     }
@@ -228,13 +271,22 @@ impl MlnxFan {
 
     fn write_sysfs_value(&self, filename: &str, value: &str) -> Result<()> {
         let path = self.hwmon_path.join(filename);
-        fs::write(&path, value)
+        self.backend
+            .write(&path, value.as_bytes())
             .with_context(|| format!("Failed to write to {}", path.display()))
     }
 
     fn rpm_to_percentage(&self, rpm: u32) -> u32 {
-        const MAX_RPM: u32 = 25000;
-        ((rpm as f32 / MAX_RPM as f32) * 100.0).min(100.0) as u32
+        // Scale against this fan's kernel-provided `fanN_max` ceiling when
+        // available, so the percentage reflects its real top speed rather than
+        // a one-size-fits-all constant. `fanN_min` is the low-speed alarm
+        // threshold, not the zero of the scale, so it is deliberately not
+        // subtracted here.
+        let max = self.max_rpm.unwrap_or(FALLBACK_MAX_RPM);
+        if max == 0 {
+            return ((rpm as f32 / FALLBACK_MAX_RPM as f32) * 100.0).min(100.0) as u32;
+        }
+        ((rpm as f32 / max as f32) * 100.0).clamp(0.0, 100.0) as u32
     }
 
     fn pwm_to_percentage(&self, pwm: u32) -> u32 {
@@ -244,11 +296,33 @@ impl MlnxFan {
     fn percentage_to_pwm(&self, percentage: u32) -> u32 {
         ((percentage.min(100) as f32 / 100.0) * 255.0) as u32
     }
+
+    /// Drive this fan to the given speed percentage via its PWM channel.
+    ///
+    /// Control loops call this with a speed produced by a [`FanCurve`](crate::fan_curve::FanCurve)
+    /// or a PID loop; the percentage is written through [`set_pwm`](Self::set_pwm).
+    /// Fans without a PWM channel cannot be actuated and return an error.
+    pub fn set_target(&self, speed_percentage: u32) -> Result<()> {
+        self.set_pwm(speed_percentage)
+    }
+
+    /// Write the given speed percentage to this fan's PWM channel.
+    pub fn set_pwm(&self, speed_percentage: u32) -> Result<()> {
+        let pwm_idx = self
+            .pwm_index
+            .context("fan has no PWM channel to actuate")?;
+        let pwm = self.percentage_to_pwm(speed_percentage);
+        self.write_sysfs_value(&format!("pwm{}", pwm_idx), &pwm.to_string())
+    }
 }
 
 impl Fan for MlnxFan {
     fn get_name(&self) -> Result<String> {
-        Ok(self.name.clone())
+        let label_file = format!("fan{}_label", self.fan_index);
+        match self.read_sysfs_value(&label_file) {
+            Ok(label) if !label.is_empty() => Ok(label),
+            _ => Ok(self.name.clone()),
+        }
     }
 
     fn get_presence(&self) -> Result<bool> {
@@ -276,6 +350,12 @@ impl Fan for MlnxFan {
     }
 
     fn get_target_speed(&self) -> Result<u32> {
+        // Prefer the kernel's commanded RPM when exposed, scaling it the same
+        // way as the measured speed.
+        let target_file = format!("fan{}_target", self.fan_index);
+        if let Ok(rpm) = self.read_sysfs_u32(&target_file) {
+            return Ok(self.rpm_to_percentage(rpm));
+        }
         if let Some(pwm_idx) = self.pwm_index {
             let pwm_file = format!("pwm{}", pwm_idx);
             let pwm = self.read_sysfs_u32(&pwm_file)?;
@@ -288,17 +368,15 @@ impl Fan for MlnxFan {
     fn is_under_speed(&self) -> Result<bool> {
         let speed = self.get_speed()?;
         let target = self.get_target_speed()?;
-        const TOLERANCE: u32 = 20;
 
-        Ok(speed < target.saturating_sub(TOLERANCE))
+        Ok(speed < target.saturating_sub(self.tolerance))
     }
 
     fn is_over_speed(&self) -> Result<bool> {
         let speed = self.get_speed()?;
         let target = self.get_target_speed()?;
-        const TOLERANCE: u32 = 20;
 
-        Ok(speed > target.saturating_add(TOLERANCE))
+        Ok(speed > target.saturating_add(self.tolerance))
     }
 
     fn get_direction(&self) -> Result<FanDirection> {
@@ -306,9 +384,8 @@ impl Fan for MlnxFan {
     }
 
     fn get_model(&self) -> Result<String> {
-        let name_file = self.hwmon_path.join("name");
-        match fs::read_to_string(&name_file) {
-            Ok(name) => Ok(name.trim().to_string()),
+        match self.read_sysfs_value("name") {
+            Ok(name) => Ok(name),
             Err(_) => Ok("Mellanox Fan".to_string()),
         }
     }
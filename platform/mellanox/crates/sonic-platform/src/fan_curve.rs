@@ -0,0 +1,158 @@
+use anyhow::{bail, Result};
+use serde::Deserialize;
+
+/// A single anchor point on a fan curve: between two adjacent points the speed
+/// is linearly interpolated.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct MatrixPoint {
+    /// Driving temperature in degrees Celsius.
+    pub temp: f32,
+    /// Commanded speed as a percentage in `0..=100`.
+    pub speed: f32,
+}
+
+/// Configurable thermal policy mapping a sensor temperature to a fan speed.
+///
+/// The matrix is kept sorted by ascending temperature. `speed_for_temp` finds
+/// the last point whose `temp <= current` and linearly interpolates towards the
+/// next point, clamping below the first point and above the last point.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FanCurve {
+    /// Sorted anchor points driving the interpolation.
+    matrix: Vec<MatrixPoint>,
+    /// Lower bound applied to every interpolated result.
+    #[serde(default)]
+    min_speed: f32,
+}
+
+impl FanCurve {
+    /// Build a curve from anchor points, sorting and validating them.
+    pub fn new(mut matrix: Vec<MatrixPoint>, min_speed: f32) -> Result<Self> {
+        matrix.sort_by(|a, b| a.temp.partial_cmp(&b.temp).unwrap_or(std::cmp::Ordering::Equal));
+        let curve = Self { matrix, min_speed };
+        curve.validate()?;
+        Ok(curve)
+    }
+
+    /// Reject empty, non-monotonic, or out-of-range configurations.
+    pub fn validate(&self) -> Result<()> {
+        if self.matrix.is_empty() {
+            bail!("fan curve must contain at least one point");
+        }
+
+        if !(0.0..=100.0).contains(&self.min_speed) {
+            bail!("min_speed {} out of range 0..=100", self.min_speed);
+        }
+
+        let mut prev: Option<f32> = None;
+        for point in &self.matrix {
+            if !(0.0..=100.0).contains(&point.speed) {
+                bail!("speed {} out of range 0..=100", point.speed);
+            }
+            if let Some(prev_temp) = prev {
+                if point.temp <= prev_temp {
+                    bail!("fan curve temperatures must be strictly increasing");
+                }
+            }
+            prev = Some(point.temp);
+        }
+
+        Ok(())
+    }
+
+    /// Interpolate the target speed percentage for the given temperature.
+    pub fn speed_for_temp(&self, temp: f32) -> u32 {
+        // `validate` guarantees at least one point.
+        let first = self.matrix[0];
+        let last = self.matrix[self.matrix.len() - 1];
+
+        let speed = if temp <= first.temp {
+            first.speed
+        } else if temp >= last.temp {
+            100.0
+        } else {
+            // Find the last point whose temp <= current and interpolate towards the next.
+            let mut result = last.speed;
+            for window in self.matrix.windows(2) {
+                let lo = window[0];
+                let hi = window[1];
+                if temp >= lo.temp && temp < hi.temp {
+                    result = lo.speed
+                        + (hi.speed - lo.speed) * (temp - lo.temp) / (hi.temp - lo.temp);
+                    break;
+                }
+            }
+            result
+        };
+
+        speed.max(self.min_speed).clamp(0.0, 100.0).round() as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(temp: f32, speed: f32) -> MatrixPoint {
+        MatrixPoint { temp, speed }
+    }
+
+    /// A three-point curve used across the interpolation tests.
+    fn curve() -> FanCurve {
+        FanCurve::new(
+            vec![point(30.0, 20.0), point(50.0, 60.0), point(70.0, 90.0)],
+            0.0,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn below_first_point_holds_first_speed() {
+        assert_eq!(curve().speed_for_temp(10.0), 20);
+    }
+
+    #[test]
+    fn above_last_point_commands_full_speed() {
+        assert_eq!(curve().speed_for_temp(90.0), 100);
+    }
+
+    #[test]
+    fn exact_anchor_returns_anchor_speed() {
+        assert_eq!(curve().speed_for_temp(50.0), 60);
+    }
+
+    #[test]
+    fn mid_segment_interpolates_linearly() {
+        // Halfway between (30,20) and (50,60) -> 40%.
+        assert_eq!(curve().speed_for_temp(40.0), 40);
+    }
+
+    #[test]
+    fn min_speed_acts_as_a_floor() {
+        let curve = FanCurve::new(vec![point(30.0, 10.0), point(70.0, 90.0)], 35.0).unwrap();
+        // Below the first point the raw speed is 10%, lifted to the 35% floor.
+        assert_eq!(curve.speed_for_temp(0.0), 35);
+    }
+
+    #[test]
+    fn empty_matrix_is_rejected() {
+        assert!(FanCurve::new(Vec::new(), 0.0).is_err());
+    }
+
+    #[test]
+    fn non_monotonic_matrix_is_rejected() {
+        let err = FanCurve::new(vec![point(50.0, 60.0), point(30.0, 20.0), point(30.0, 40.0)], 0.0);
+        // Sorting leaves two equal temperatures, which must be rejected.
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn out_of_range_speed_is_rejected() {
+        assert!(FanCurve::new(vec![point(30.0, 120.0)], 0.0).is_err());
+    }
+
+    #[test]
+    fn out_of_range_min_speed_is_rejected() {
+        assert!(FanCurve::new(vec![point(30.0, 20.0)], 150.0).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::Result;
+use tracing::warn;
+
+use crate::fan::MlnxFan;
+use crate::fan_curve::FanCurve;
+
+/// One sensor's reading handed to the governor each tick.
+#[derive(Debug, Clone, Copy)]
+pub struct ZoneReading {
+    /// Current temperature, or `None` if the read failed.
+    pub temperature: Option<f32>,
+    /// Critical threshold for this zone, if known.
+    pub critical: Option<f32>,
+}
+
+/// Closed-loop thermal fan governor.
+///
+/// Each tick it takes the hottest raw temperature (readings are compared in
+/// °C as given, without per-zone normalization), maps it to a target PWM
+/// through a piecewise-linear [`FanCurve`], then applies a dead-band and a
+/// per-tick rate limiter to avoid oscillation. If any sensor crosses its
+/// critical threshold or fails to read, it forces PWM to 100% (fail-safe) and
+/// surfaces a warning.
+pub struct ThermalGovernor {
+    curve: FanCurve,
+    /// ±band (°C) around the last set point within which PWM is held.
+    hysteresis: f32,
+    /// Maximum PWM percentage change applied in a single tick.
+    max_step: u32,
+    last_pwm: u32,
+    last_temp: Option<f32>,
+}
+
+impl ThermalGovernor {
+    pub fn new(curve: FanCurve, hysteresis: f32, max_step: u32) -> Self {
+        Self {
+            curve,
+            hysteresis,
+            max_step,
+            last_pwm: 100,
+            last_temp: None,
+        }
+    }
+
+    /// Evaluate the zones and drive the fan, returning the commanded PWM.
+    pub fn tick(&mut self, zones: &[ZoneReading], fan: &MlnxFan) -> Result<u32> {
+        // Fail-safe: with nothing to evaluate we cannot prove the box is cool,
+        // so force full speed rather than ramping down to the curve floor.
+        if zones.is_empty() {
+            warn!("no thermal zones to evaluate; forcing fans to 100%");
+            return self.force_full(fan);
+        }
+
+        // Fail-safe: a missing reading or a critical crossing forces full speed.
+        let mut hottest: Option<f32> = None;
+        for zone in zones {
+            match zone.temperature {
+                None => {
+                    warn!("thermal sensor read failed; forcing fans to 100%");
+                    return self.force_full(fan);
+                }
+                Some(temp) => {
+                    if let Some(crit) = zone.critical {
+                        if temp >= crit {
+                            warn!("sensor at {}°C exceeds critical {}°C; forcing 100%", temp, crit);
+                            return self.force_full(fan);
+                        }
+                    }
+                    hottest = Some(hottest.map_or(temp, |h: f32| h.max(temp)));
+                }
+            }
+        }
+
+        let Some(hottest) = hottest else {
+            warn!("no usable thermal readings this tick; forcing fans to 100%");
+            return self.force_full(fan);
+        };
+
+        // Dead-band: hold the last PWM while the temperature stays within the
+        // hysteresis window around the previous set point.
+        if let Some(prev) = self.last_temp {
+            if (hottest - prev).abs() <= self.hysteresis {
+                fan.set_pwm(self.last_pwm)?;
+                return Ok(self.last_pwm);
+            }
+        }
+
+        let target = self.curve.speed_for_temp(hottest);
+        let stepped = self.rate_limit(target);
+
+        fan.set_pwm(stepped)?;
+        self.last_pwm = stepped;
+        self.last_temp = Some(hottest);
+        Ok(stepped)
+    }
+
+    fn force_full(&mut self, fan: &MlnxFan) -> Result<u32> {
+        fan.set_pwm(100)?;
+        self.last_pwm = 100;
+        self.last_temp = None;
+        Ok(100)
+    }
+
+    /// Clamp the requested PWM to at most `max_step` away from the last value.
+    fn rate_limit(&self, target: u32) -> u32 {
+        if target > self.last_pwm {
+            target.min(self.last_pwm + self.max_step)
+        } else {
+            target.max(self.last_pwm.saturating_sub(self.max_step))
+        }
+    }
+}
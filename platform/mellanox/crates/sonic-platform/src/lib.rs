@@ -1,37 +1,59 @@
+pub mod adapter;
 pub mod chassis;
+pub mod config;
+pub mod eeprom;
 pub mod fan;
+pub mod fan_curve;
+pub mod governor;
+pub mod pid;
+pub mod platform;
+pub mod sysfs;
 pub mod thermal;
+pub mod vendor;
 
+pub use adapter::{Adapter, DevModeFan, HwmonAdapter, SensorReading, Settings};
 pub use chassis::MlnxChassis;
+pub use config::{PlatformConfig, ThermalDescriptor};
+pub use eeprom::SysEeprom;
+pub use platform::{detect_platform, detect_platform_with, Platform};
+pub use sysfs::{LiveSysfs, MockSysfs, SharedSysfs, SysfsBackend};
+pub use vendor::{Chassis, MellanoxDetector, MellanoxPlatform, PlatformDetector};
 pub use fan::{
     MlnxFan, Fan, FanDirection, FanDrawer, FanStatus, LedColor, set_fan_speed
 };
+pub use fan_curve::{FanCurve, MatrixPoint};
+pub use governor::{ThermalGovernor, ZoneReading};
+pub use pid::{PidConfig, PidFanController};
 pub use thermal::{MlnxThermal, Thermal, TemperatureStatus};
 
 use anyhow::Result;
-use std::fs;
+use std::path::Path;
 
-pub fn detect_platform() -> bool {
-    is_mellanox_platform()
-}
+use crate::sysfs::{LiveSysfs, SysfsBackend};
 
 pub fn is_mellanox_platform() -> bool {
-    if let Ok(dmi_board_vendor) = fs::read_to_string("/sys/class/dmi/id/board_vendor") {
+    is_mellanox_platform_with(&LiveSysfs)
+}
+
+/// Probe for a Mellanox platform through the supplied backend, so detection can
+/// run against a recorded sysfs snapshot in CI.
+pub fn is_mellanox_platform_with(backend: &dyn SysfsBackend) -> bool {
+    if let Ok(dmi_board_vendor) = backend.read_to_string(Path::new("/sys/class/dmi/id/board_vendor")) {
         if dmi_board_vendor.to_lowercase().contains("mellanox") {
             return true;
         }
     }
 
-    if let Ok(dmi_sys_vendor) = fs::read_to_string("/sys/class/dmi/id/sys_vendor") {
+    if let Ok(dmi_sys_vendor) = backend.read_to_string(Path::new("/sys/class/dmi/id/sys_vendor")) {
         if dmi_sys_vendor.to_lowercase().contains("mellanox")
             || dmi_sys_vendor.to_lowercase().contains("nvidia") {
             return true;
         }
     }
 
-    if let Ok(entries) = fs::read_dir("/sys/class/hwmon") {
-        for entry in entries.flatten() {
-            if let Ok(name) = fs::read_to_string(entry.path().join("name")) {
+    if let Ok(entries) = backend.read_dir(Path::new("/sys/class/hwmon")) {
+        for entry in entries {
+            if let Ok(name) = backend.read_to_string(&entry.join("name")) {
                 if name.trim().contains("mlxsw") {
                     return true;
                 }
@@ -0,0 +1,89 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::fan::{Fan, MlnxFan};
+
+/// Tunables for the closed-loop PID fan controller, loaded from config.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PidConfig {
+    /// Proportional gain.
+    pub kp: f32,
+    /// Integral gain.
+    pub ki: f32,
+    /// Derivative gain.
+    pub kd: f32,
+    /// Target temperature the loop regulates towards, in degrees Celsius.
+    pub setpoint: f32,
+}
+
+/// Closed-loop PID controller driving fan PWM from the temperature error.
+///
+/// On each tick it takes the driving sensor's temperature, computes the PID
+/// output, clamps it to `0..=100`, and commands the fan. It is an alternative to
+/// the static [`FanCurve`](crate::fan_curve::FanCurve) mapping.
+pub struct PidFanController {
+    config: PidConfig,
+    integral: f32,
+    prev_error: f32,
+}
+
+impl PidFanController {
+    pub fn new(config: PidConfig) -> Self {
+        Self {
+            config,
+            integral: 0.0,
+            prev_error: 0.0,
+        }
+    }
+
+    /// Clear the accumulated integral and error history.
+    ///
+    /// Called when the fan leaves presence so a re-inserted drawer does not
+    /// inherit stale windup.
+    pub fn reset(&mut self) {
+        self.integral = 0.0;
+        self.prev_error = 0.0;
+    }
+
+    /// Compute the next PWM percentage for the given temperature and timestep.
+    ///
+    /// A non-positive `dt` skips the integral/derivative update to avoid a
+    /// divide-by-zero: for that tick the integral keeps its stored value and the
+    /// derivative term is zero.
+    pub fn compute(&mut self, temperature: f32, dt: f32) -> u32 {
+        let error = temperature - self.config.setpoint;
+
+        let (integral, derivative) = if dt > 0.0 {
+            let integral = self.integral + error * dt;
+            let derivative = (error - self.prev_error) / dt;
+            (integral, derivative)
+        } else {
+            (self.integral, 0.0)
+        };
+
+        let output = self.config.kp * error
+            + self.config.ki * integral
+            + self.config.kd * derivative;
+        let clamped = output.clamp(0.0, 100.0);
+
+        // Anti-windup: only retain the integral term while the output is not
+        // saturated, preventing it from growing unbounded at the rails.
+        if dt > 0.0 && (0.0..=100.0).contains(&output) {
+            self.integral = integral;
+        }
+        self.prev_error = error;
+
+        clamped.round() as u32
+    }
+
+    /// Compute the output from the driving sensor's `temperature` and command
+    /// the fan. An absent fan resets the controller and is not commanded.
+    pub fn tick(&mut self, fan: &MlnxFan, temperature: f32, dt: f32) -> Result<()> {
+        if !fan.get_presence().unwrap_or(false) {
+            self.reset();
+            return Ok(());
+        }
+        let speed = self.compute(temperature, dt);
+        fan.set_target(speed)
+    }
+}
@@ -0,0 +1,146 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::sysfs::{LiveSysfs, SysfsBackend};
+
+/// A detected Mellanox/NVIDIA switch SKU.
+///
+/// Fan counts and thermal layouts differ per board, so the chassis/fan/thermal
+/// modules need the concrete model rather than a bare "is Mellanox" bool. When
+/// the DMI and hwmon signatures disagree we deliberately surface
+/// [`Platform::Ambiguous`] instead of guessing, mirroring board-selection logic
+/// that fails closed on an unknown board so callers can log and degrade.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Platform {
+    Msn2700,
+    Sn3800,
+    Sn4600,
+    /// No recognised Mellanox signature was found.
+    Unknown,
+    /// Multiple conflicting signatures matched; caller should not assume a SKU.
+    Ambiguous,
+}
+
+impl Platform {
+    /// Whether this variant names a concrete, supported board.
+    pub fn is_known(&self) -> bool {
+        !matches!(self, Platform::Unknown | Platform::Ambiguous)
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Platform::Msn2700 => "MSN2700",
+            Platform::Sn3800 => "SN3800",
+            Platform::Sn4600 => "SN4600",
+            Platform::Unknown => "Unknown",
+            Platform::Ambiguous => "Ambiguous",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Match a known SKU anywhere in a free-form identifier string (a DMI
+/// product/board name, or an hwmon board label).
+fn sku_from_identifier(identifier: &str) -> Option<Platform> {
+    let identifier = identifier.to_lowercase();
+    if identifier.contains("msn2700") {
+        Some(Platform::Msn2700)
+    } else if identifier.contains("sn3800") {
+        Some(Platform::Sn3800)
+    } else if identifier.contains("sn4600") {
+        Some(Platform::Sn4600)
+    } else {
+        None
+    }
+}
+
+/// Derive a SKU from the board identifier exposed by the `mlxsw` hwmon device,
+/// independent of the DMI product name. Returns `None` when no such device is
+/// present or its label names no recognised board.
+fn sku_from_hwmon(backend: &dyn SysfsBackend) -> Option<Platform> {
+    let entries = backend.read_dir(Path::new("/sys/class/hwmon")).ok()?;
+    for entry in entries {
+        let Ok(name) = backend.read_to_string(&entry.join("name")) else {
+            continue;
+        };
+        if !name.trim().contains("mlxsw") {
+            continue;
+        }
+        // The board label sits alongside the device name on Mellanox hwmon.
+        if let Ok(label) = backend.read_to_string(&entry.join("board")) {
+            if let Some(sku) = sku_from_identifier(&label) {
+                return Some(sku);
+            }
+        }
+    }
+    None
+}
+
+/// Detect the running platform against the live sysfs tree.
+pub fn detect_platform() -> Platform {
+    detect_platform_with(&LiveSysfs)
+}
+
+/// Detect the running platform by combining DMI strings with hwmon presence,
+/// reading through the supplied backend so detection is testable off hardware.
+pub fn detect_platform_with(backend: &dyn SysfsBackend) -> Platform {
+    let product = backend
+        .read_to_string(Path::new("/sys/class/dmi/id/product_name"))
+        .unwrap_or_default();
+
+    // Two independent SKU signatures: the DMI product name and the board label
+    // reported by the mlxsw hwmon device. Reconcile them rather than trusting a
+    // single source. A plain vendor string (handled by `is_mellanox_platform`)
+    // only establishes that this is a Mellanox host, never a specific board, so
+    // it cannot corroborate or contradict a SKU here.
+    let dmi_sku = sku_from_identifier(&product);
+    let hwmon_sku = sku_from_hwmon(backend);
+
+    match (dmi_sku, hwmon_sku) {
+        // Both signatures name a board but disagree: genuinely conflicting.
+        (Some(a), Some(b)) if a != b => Platform::Ambiguous,
+        // Both agree, or only one source names a board: trust it.
+        (Some(sku), Some(_)) | (Some(sku), None) | (None, Some(sku)) => sku,
+        // No SKU string at all; at most we know the vendor, not the board.
+        (None, None) => Platform::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sysfs::MockSysfs;
+
+    #[test]
+    fn agreeing_signatures_resolve_to_the_sku() {
+        let backend = MockSysfs::new()
+            .with_file("/sys/class/dmi/id/product_name", "MSN2700")
+            .with_file("/sys/class/hwmon/hwmon0/name", "mlxsw")
+            .with_file("/sys/class/hwmon/hwmon0/board", "MSN2700");
+        assert_eq!(detect_platform_with(&backend), Platform::Msn2700);
+    }
+
+    #[test]
+    fn conflicting_signatures_are_ambiguous() {
+        let backend = MockSysfs::new()
+            .with_file("/sys/class/dmi/id/product_name", "MSN2700")
+            .with_file("/sys/class/hwmon/hwmon0/name", "mlxsw")
+            .with_file("/sys/class/hwmon/hwmon0/board", "SN3800");
+        assert_eq!(detect_platform_with(&backend), Platform::Ambiguous);
+    }
+
+    #[test]
+    fn a_single_source_is_trusted() {
+        let backend =
+            MockSysfs::new().with_file("/sys/class/dmi/id/product_name", "SN4600 switch");
+        assert_eq!(detect_platform_with(&backend), Platform::Sn4600);
+    }
+
+    #[test]
+    fn no_signature_is_unknown() {
+        let backend = MockSysfs::new().with_file("/sys/class/dmi/id/product_name", "Generic x86");
+        assert_eq!(detect_platform_with(&backend), Platform::Unknown);
+    }
+}
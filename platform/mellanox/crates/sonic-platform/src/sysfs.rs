@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Abstraction over the sysfs reads and writes performed by the detection and
+/// driver code, so the whole stack can be exercised against recorded snapshots
+/// of real switches in CI without any hardware.
+pub trait SysfsBackend: Send + Sync {
+    /// Read a file to a `String`.
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+
+    /// List the entries of a directory as full paths.
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
+
+    /// Write bytes to a file.
+    fn write(&self, path: &Path, bytes: &[u8]) -> io::Result<()>;
+
+    /// Whether the given path exists.
+    fn exists(&self, path: &Path) -> bool {
+        self.read_to_string(path).is_ok()
+    }
+}
+
+/// A handle to the active backend, cheap to clone and share between devices.
+pub type SharedSysfs = Arc<dyn SysfsBackend>;
+
+/// The production backend, delegating straight to `std::fs`.
+#[derive(Debug, Default)]
+pub struct LiveSysfs;
+
+impl SysfsBackend for LiveSysfs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut out = Vec::new();
+        for entry in std::fs::read_dir(path)? {
+            out.push(entry?.path());
+        }
+        Ok(out)
+    }
+
+    fn write(&self, path: &Path, bytes: &[u8]) -> io::Result<()> {
+        std::fs::write(path, bytes)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+/// Return a shared [`LiveSysfs`] for the default production path.
+pub fn live() -> SharedSysfs {
+    Arc::new(LiveSysfs)
+}
+
+/// An in-memory backend serving a fixture tree of file contents.
+#[derive(Debug, Default)]
+pub struct MockSysfs {
+    files: HashMap<PathBuf, String>,
+}
+
+impl MockSysfs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Insert a file into the fixture tree, returning `self` for chaining.
+    pub fn with_file(mut self, path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        self.files.insert(path.into(), contents.into());
+        self
+    }
+}
+
+impl SysfsBackend for MockSysfs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.display().to_string()))
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut out: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|p| p.parent() == Some(path))
+            .cloned()
+            .collect();
+        // Also surface immediate subdirectories of `path`.
+        for key in self.files.keys() {
+            if let Ok(rest) = key.strip_prefix(path) {
+                if let Some(first) = rest.components().next() {
+                    let child = path.join(first);
+                    if &child != path && !out.contains(&child) && child.parent() == Some(path) {
+                        out.push(child);
+                    }
+                }
+            }
+        }
+        out.sort();
+        out.dedup();
+        Ok(out)
+    }
+
+    fn write(&self, _path: &Path, _bytes: &[u8]) -> io::Result<()> {
+        // Writes are accepted but discarded; the fixture tree is immutable.
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.contains_key(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chassis::MlnxChassis;
+    use crate::platform::{detect_platform_with, Platform};
+
+    /// A minimal recorded snapshot of an MSN2700's sysfs tree: one mlxsw hwmon
+    /// device exposing a thermal sensor, a fan tacho, and a PWM channel.
+    fn msn2700_fixture() -> MockSysfs {
+        MockSysfs::new()
+            .with_file("/sys/class/dmi/id/product_name", "MSN2700")
+            .with_file("/sys/class/hwmon/hwmon0/name", "mlxsw")
+            .with_file("/sys/class/hwmon/hwmon0/board", "MSN2700")
+            .with_file("/sys/class/hwmon/hwmon0/temp1_input", "42000")
+            .with_file("/sys/class/hwmon/hwmon0/fan1_input", "5100")
+            .with_file("/sys/class/hwmon/hwmon0/pwm1", "128")
+    }
+
+    #[test]
+    fn detection_and_discovery_run_against_a_recorded_snapshot() {
+        let fixture = msn2700_fixture();
+        assert_eq!(detect_platform_with(&fixture), Platform::Msn2700);
+
+        let chassis = MlnxChassis::with_backend(Arc::new(msn2700_fixture()))
+            .expect("chassis builds from the fixture backend");
+        assert_eq!(chassis.get_thermals().len(), 1);
+        assert_eq!(chassis.get_fans().len(), 1);
+    }
+}
@@ -1,24 +1,37 @@
 use anyhow::{Context, Result};
-use std::fs;
 use std::path::PathBuf;
 
+use crate::sysfs::SharedSysfs;
+
 pub trait Thermal: Send + Sync {
     fn get_name(&self) -> Result<String>;
 
-    fn get_temperature(&self) -> Result<f32>;
+    /// Current temperature, or `None` when the `tempN_input` file is missing.
+    fn get_temperature(&self) -> Result<Option<f32>>;
 
-    fn get_high_threshold(&self) -> Result<f32>;
+    /// High (warning) threshold, or `None` when no `tempN_max` file exists.
+    fn get_high_threshold(&self) -> Result<Option<f32>>;
 
-    fn get_low_threshold(&self) -> Result<f32>;
+    /// Low threshold, or `None` when no `tempN_min` file exists.
+    fn get_low_threshold(&self) -> Result<Option<f32>>;
 
-    fn get_high_critical_threshold(&self) -> Result<f32>;
+    /// Critical threshold, or `None` when no `tempN_crit` file exists.
+    fn get_high_critical_threshold(&self) -> Result<Option<f32>>;
 
-    fn get_low_critical_threshold(&self) -> Result<f32>;
+    /// Low critical threshold, or `None` when no `tempN_lcrit` file exists.
+    fn get_low_critical_threshold(&self) -> Result<Option<f32>>;
 
     fn get_minimum_recorded(&self) -> Result<f32>;
 
     fn get_maximum_recorded(&self) -> Result<f32>;
 
+    /// Model string of the device backing this sensor, resolved from the hwmon
+    /// `device` symlink (e.g. "MSN2700").
+    fn get_device_model(&self) -> Result<String>;
+
+    /// Short chip name reported by the hwmon `name` file (e.g. "mlxsw").
+    fn get_chip_name(&self) -> Result<String>;
+
     fn is_replaceable(&self) -> Result<bool>;
 
     fn get_position_in_parent(&self) -> Result<usize>;
@@ -60,12 +73,11 @@ impl TemperatureStatus {
         changed
     }
 
-    pub fn set_over_temperature(&mut self, temperature: f32, threshold: f32) -> bool {
-        const NOT_AVAILABLE: f32 = -999.0;
-
-        if (temperature - NOT_AVAILABLE).abs() < 0.1 || (threshold - NOT_AVAILABLE).abs() < 0.1 {
-            return false;
-        }
+    pub fn set_over_temperature(&mut self, temperature: Option<f32>, threshold: Option<f32>) -> bool {
+        let (temperature, threshold) = match (temperature, threshold) {
+            (Some(t), Some(th)) => (t, th),
+            _ => return false,
+        };
 
         let new_status = temperature > threshold;
         let changed = self.over_temperature != new_status;
@@ -73,12 +85,11 @@ impl TemperatureStatus {
         changed
     }
 
-    pub fn set_under_temperature(&mut self, temperature: f32, threshold: f32) -> bool {
-        const NOT_AVAILABLE: f32 = -999.0;
-
-        if (temperature - NOT_AVAILABLE).abs() < 0.1 || (threshold - NOT_AVAILABLE).abs() < 0.1 {
-            return false;
-        }
+    pub fn set_under_temperature(&mut self, temperature: Option<f32>, threshold: Option<f32>) -> bool {
+        let (temperature, threshold) = match (temperature, threshold) {
+            (Some(t), Some(th)) => (t, th),
+            _ => return false,
+        };
 
         let new_status = temperature < threshold;
         let changed = self.under_temperature != new_status;
@@ -99,22 +110,53 @@ pub struct MlnxThermal {
     temp_index: usize,
     min_temp: f32,
     max_temp: f32,
+    warning_override: Option<f32>,
+    critical_override: Option<f32>,
+    backend: SharedSysfs,
 }
 
 impl MlnxThermal {
+    /// Construct a thermal sensor backed by the live sysfs backend.
     pub fn new(name: String, hwmon_path: PathBuf, temp_index: usize) -> Self {
+        Self::with_backend(name, hwmon_path, temp_index, crate::sysfs::live())
+    }
+
+    /// Construct a thermal sensor backed by the supplied
+    /// [`SysfsBackend`](crate::sysfs::SysfsBackend).
+    pub fn with_backend(
+        name: String,
+        hwmon_path: PathBuf,
+        temp_index: usize,
+        backend: SharedSysfs,
+    ) -> Self {
         Self {
             name,
             hwmon_path,
             temp_index,
             min_temp: 1000.0,
             max_temp: -1000.0,
+            warning_override: None,
+            critical_override: None,
+            backend,
         }
     }
 
+    /// Apply the name and thresholds described in the platform descriptor.
+    ///
+    /// The warning and critical thresholds replace the `tempN_max`/`tempN_crit`
+    /// readings. The name is used only when the sensor has no `tempN_label`,
+    /// and the shutdown threshold is not applied.
+    pub fn with_descriptor(mut self, desc: &crate::config::ThermalDescriptor) -> Self {
+        self.name = desc.name.clone();
+        self.warning_override = desc.warning;
+        self.critical_override = desc.critical;
+        self
+    }
+
     fn read_sysfs_value(&self, filename: &str) -> Result<String> {
         let path = self.hwmon_path.join(filename);
-        fs::read_to_string(&path)
+        self.backend
+            .read_to_string(&path)
             .with_context(|| format!("Failed to read {}", path.display()))
             .map(|s| s.trim().to_string())
     }
@@ -127,6 +169,24 @@ impl MlnxThermal {
         Ok(millidegrees as f32 / 1000.0)
     }
 
+    /// Read a file under the `device` directory of this hwmon node.
+    fn read_device_attr(&self, filename: &str) -> Result<String> {
+        let path = self.hwmon_path.join("device").join(filename);
+        self.backend
+            .read_to_string(&path)
+            .with_context(|| format!("Failed to read device/{}", filename))
+            .map(|s| s.trim().to_string())
+    }
+
+    /// Read a temperature file, yielding `None` when it is absent and an error
+    /// only when it exists but fails to parse.
+    fn read_optional_temp(&self, filename: &str) -> Result<Option<f32>> {
+        if !self.backend.exists(&self.hwmon_path.join(filename)) {
+            return Ok(None);
+        }
+        self.read_sysfs_temp(filename).map(Some)
+    }
+
     fn update_min_max(&mut self, temp: f32) {
         if temp < self.min_temp {
             self.min_temp = temp;
@@ -146,39 +206,30 @@ impl Thermal for MlnxThermal {
         }
     }
 
-    fn get_temperature(&self) -> Result<f32> {
-        let input_file = format!("temp{}_input", self.temp_index);
-        let temp = self.read_sysfs_temp(&input_file)?;
-
-        Ok(temp)
+    fn get_temperature(&self) -> Result<Option<f32>> {
+        self.read_optional_temp(&format!("temp{}_input", self.temp_index))
     }
 
-    fn get_high_threshold(&self) -> Result<f32> {
-        let max_file = format!("temp{}_max", self.temp_index);
-        match self.read_sysfs_temp(&max_file) {
-            Ok(temp) => Ok(temp),
-            Err(_) => Ok(85.0),
+    fn get_high_threshold(&self) -> Result<Option<f32>> {
+        if self.warning_override.is_some() {
+            return Ok(self.warning_override);
         }
+        self.read_optional_temp(&format!("temp{}_max", self.temp_index))
     }
 
-    fn get_low_threshold(&self) -> Result<f32> {
-        let min_file = format!("temp{}_min", self.temp_index);
-        match self.read_sysfs_temp(&min_file) {
-            Ok(temp) => Ok(temp),
-            Err(_) => Ok(0.0),
-        }
+    fn get_low_threshold(&self) -> Result<Option<f32>> {
+        self.read_optional_temp(&format!("temp{}_min", self.temp_index))
     }
 
-    fn get_high_critical_threshold(&self) -> Result<f32> {
-        let crit_file = format!("temp{}_crit", self.temp_index);
-        match self.read_sysfs_temp(&crit_file) {
-            Ok(temp) => Ok(temp),
-            Err(_) => Ok(100.0),
+    fn get_high_critical_threshold(&self) -> Result<Option<f32>> {
+        if self.critical_override.is_some() {
+            return Ok(self.critical_override);
         }
+        self.read_optional_temp(&format!("temp{}_crit", self.temp_index))
     }
 
-    fn get_low_critical_threshold(&self) -> Result<f32> {
-        Ok(-10.0)
+    fn get_low_critical_threshold(&self) -> Result<Option<f32>> {
+        self.read_optional_temp(&format!("temp{}_lcrit", self.temp_index))
     }
 
     fn get_minimum_recorded(&self) -> Result<f32> {
@@ -197,6 +248,15 @@ impl Thermal for MlnxThermal {
         }
     }
 
+    fn get_device_model(&self) -> Result<String> {
+        self.read_device_attr("model")
+            .or_else(|_| self.read_device_attr("modalias"))
+    }
+
+    fn get_chip_name(&self) -> Result<String> {
+        self.read_sysfs_value("name")
+    }
+
     fn is_replaceable(&self) -> Result<bool> {
         Ok(false)
     }
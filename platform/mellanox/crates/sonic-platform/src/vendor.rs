@@ -0,0 +1,106 @@
+use anyhow::Result;
+
+use crate::chassis::MlnxChassis;
+use crate::platform::{detect_platform, Platform as Sku};
+
+/// Vendor-agnostic view of a switch chassis.
+///
+/// [`MlnxChassis`] implements this, and new vendors implement it for their own
+/// chassis type, so callers can program against the trait rather than the
+/// concrete `Mlnx*` types.
+pub trait Chassis: Send + Sync {
+    /// Number of fans discovered on this chassis.
+    fn num_fans(&self) -> usize;
+
+    /// Number of thermal sensors discovered on this chassis.
+    fn num_thermals(&self) -> usize;
+
+    /// Chassis model number, if known.
+    fn model(&self) -> Option<&str>;
+}
+
+impl Chassis for MlnxChassis {
+    fn num_fans(&self) -> usize {
+        self.get_fans().len()
+    }
+
+    fn num_thermals(&self) -> usize {
+        self.get_thermals().len()
+    }
+
+    fn model(&self) -> Option<&str> {
+        self.get_model()
+    }
+}
+
+/// A detected platform: a vendor name plus its chassis.
+pub trait Platform: Send + Sync {
+    /// Short vendor/platform name (e.g. "Mellanox MSN2700").
+    fn name(&self) -> &str;
+
+    /// The chassis backing this platform.
+    fn chassis(&self) -> &dyn Chassis;
+}
+
+/// Probes the host and, if it recognises it, yields a [`Platform`].
+///
+/// The registry iterates registered detectors in order; a new vendor registers
+/// a detector and its trait impls without touching the Mellanox code.
+pub trait PlatformDetector: Send + Sync {
+    fn detect(&self) -> Option<Box<dyn Platform>>;
+}
+
+/// Concrete Mellanox platform wrapping a discovered [`MlnxChassis`].
+pub struct MellanoxPlatform {
+    name: String,
+    chassis: MlnxChassis,
+}
+
+impl MellanoxPlatform {
+    fn new(sku: Sku) -> Result<Self> {
+        let chassis = MlnxChassis::new()?;
+        Ok(Self {
+            name: format!("Mellanox {}", sku),
+            chassis,
+        })
+    }
+}
+
+impl Platform for MellanoxPlatform {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn chassis(&self) -> &dyn Chassis {
+        &self.chassis
+    }
+}
+
+/// Detector for Mellanox/NVIDIA switches, driven by DMI + hwmon probing.
+pub struct MellanoxDetector;
+
+impl PlatformDetector for MellanoxDetector {
+    fn detect(&self) -> Option<Box<dyn Platform>> {
+        let sku = detect_platform();
+        if !sku.is_known() {
+            return None;
+        }
+        match MellanoxPlatform::new(sku) {
+            Ok(platform) => Some(Box::new(platform)),
+            Err(e) => {
+                tracing::warn!("Mellanox detected but chassis init failed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+/// The ordered registry of platform detectors (Mellanox first).
+fn registry() -> Vec<Box<dyn PlatformDetector>> {
+    vec![Box::new(MellanoxDetector)]
+}
+
+/// Probe every registered detector in order, returning the first match.
+pub fn detect() -> Option<Box<dyn Platform>> {
+    registry().iter().find_map(|detector| detector.detect())
+}